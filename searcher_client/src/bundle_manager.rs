@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+use tokio::sync::{oneshot, Mutex};
+use tonic::codegen::{Body, Bytes, StdError};
+
+use jito_protos::{
+    bundle::{bundle_result::Result as BundleResultType, rejected::Reason, BundleResult},
+    searcher::searcher_service_client::SearcherServiceClient,
+};
+use solana_sdk::transaction::VersionedTransaction;
+
+use crate::{send_bundle_no_wait, BundleRejectionError, ResilientBundleResults};
+
+/// The terminal outcome of a bundle submitted through a [`BundleManager`].
+///
+/// `Ok(())` means the block engine's bundle-result subscription reported the
+/// bundle as `Accepted` -- it does *not* mean the bundle has since been
+/// confirmed on-chain. Callers that need an on-chain landing guarantee
+/// should follow up with `get_signature_status_with_commitment` (see
+/// [`crate::send_bundle_with_confirmation`]) themselves.
+pub type BundleOutcome = Result<(), BundleRejectionError>;
+
+/// A handle to a bundle submitted through [`BundleManager::submit`]. Resolves
+/// once the bundle's terminal outcome is known.
+pub struct BundleHandle {
+    uuid: String,
+    outcome_rx: oneshot::Receiver<BundleOutcome>,
+}
+
+impl BundleHandle {
+    /// The block-engine-assigned UUID for this bundle.
+    pub fn uuid(&self) -> &str {
+        &self.uuid
+    }
+
+    /// Waits for the bundle's terminal outcome.
+    pub async fn wait(self) -> BundleOutcome {
+        self.outcome_rx
+            .await
+            .unwrap_or_else(|_| Err(BundleRejectionError::StreamLost(
+                "bundle manager dropped before a result arrived".to_string(),
+            )))
+    }
+}
+
+/// A pending bundle's slot in [`BundleManager`]'s routing table. A bundle's
+/// outcome can arrive on the result-subscription stream before `submit` has
+/// finished registering the bundle's `oneshot` (the two race over separate
+/// RPCs), so whichever side gets to a given UUID first records its half of
+/// the handshake for the other side to pick up.
+enum Slot {
+    /// `submit` is waiting on this UUID; deliver the outcome here when it arrives.
+    Waiting(oneshot::Sender<BundleOutcome>),
+    /// The outcome for this UUID already arrived before `submit` registered it.
+    Early(BundleOutcome),
+}
+
+/// Fans a single bundle-result subscription out across many concurrently
+/// in-flight bundles, so waiting on one bundle's outcome never blocks
+/// another. Owns one `SearcherServiceClient` plus one
+/// [`ResilientBundleResults`] subscription; a background task reads results
+/// off the shared stream and routes each one to the `oneshot` of the
+/// submitting [`BundleHandle`] by UUID.
+pub struct BundleManager<T> {
+    searcher_client: SearcherServiceClient<T>,
+    slots: std::sync::Arc<Mutex<HashMap<String, Slot>>>,
+}
+
+impl<T> BundleManager<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Send + 'static + Clone,
+    T::Error: Into<StdError>,
+    T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+    <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::Future: std::marker::Send,
+{
+    pub fn new(
+        searcher_client: SearcherServiceClient<T>,
+        mut bundle_results_subscription: ResilientBundleResults,
+    ) -> Self {
+        let slots: std::sync::Arc<Mutex<HashMap<String, Slot>>> =
+            std::sync::Arc::new(Mutex::new(HashMap::new()));
+
+        let slots_for_task = slots.clone();
+        tokio::spawn(async move {
+            while let Some(next) = bundle_results_subscription.recv().await {
+                match next {
+                    Ok(result) => Self::route(&slots_for_task, result).await,
+                    Err(e) => {
+                        Self::fail_all(&slots_for_task, e).await;
+                        return;
+                    }
+                }
+            }
+        });
+
+        Self {
+            searcher_client,
+            slots,
+        }
+    }
+
+    /// Submits a bundle and returns a [`BundleHandle`] that resolves once the
+    /// bundle either is accepted or is rejected (see [`BundleOutcome`]).
+    pub async fn submit(&mut self, transactions: &[VersionedTransaction]) -> Result<BundleHandle, tonic::Status> {
+        let response = send_bundle_no_wait(transactions, &mut self.searcher_client).await?;
+        let uuid = response.into_inner().uuid;
+
+        let (outcome_tx, outcome_rx) = oneshot::channel();
+        // A single lock covers both directions of the race: if the result
+        // already arrived (and was stashed as `Slot::Early`), consume it
+        // here; otherwise register `Slot::Waiting` so `route` can find it
+        // when the result does arrive. Either way, nothing is dropped.
+        let mut slots = self.slots.lock().await;
+        match slots.remove(&uuid) {
+            Some(Slot::Early(outcome)) => {
+                let _ = outcome_tx.send(outcome);
+            }
+            _ => {
+                slots.insert(uuid.clone(), Slot::Waiting(outcome_tx));
+            }
+        }
+        drop(slots);
+
+        Ok(BundleHandle { uuid, outcome_rx })
+    }
+
+    /// Submits every bundle in `transactions_list` and returns as soon as the
+    /// first one is accepted. If every bundle is rejected, returns the last
+    /// rejection observed. Tracking of any bundles still outstanding once a
+    /// result is returned is dropped, so their eventual outcomes (if any) are
+    /// discarded rather than awaited.
+    pub async fn submit_many(
+        &mut self,
+        transactions_list: &[Vec<VersionedTransaction>],
+    ) -> Result<BundleOutcome, tonic::Status> {
+        let mut handles = Vec::with_capacity(transactions_list.len());
+        for transactions in transactions_list {
+            handles.push(self.submit(transactions).await?);
+        }
+
+        let mut futs: Vec<_> = handles
+            .into_iter()
+            .map(|h| Box::pin(h.wait()))
+            .collect::<Vec<_>>();
+
+        let mut last = Err(BundleRejectionError::InternalError(
+            "submit_many called with no bundles".to_string(),
+        ));
+        while !futs.is_empty() {
+            let (result, _index, rest) = futures_util::future::select_all(futs).await;
+            let accepted = result.is_ok();
+            last = result;
+            if accepted {
+                return Ok(last);
+            }
+            futs = rest;
+        }
+
+        Ok(last)
+    }
+
+    async fn route(slots: &std::sync::Arc<Mutex<HashMap<String, Slot>>>, result: BundleResult) {
+        let outcome = match &result.result {
+            Some(BundleResultType::Accepted(_)) => Ok(()),
+            Some(BundleResultType::Rejected(rejected)) => match &rejected.reason {
+                Some(Reason::StateAuctionBidRejected(r)) => Err(BundleRejectionError::StateAuctionBidRejected(
+                    r.auction_id.clone(),
+                    r.simulated_bid_lamports,
+                )),
+                Some(Reason::WinningBatchBidRejected(r)) => Err(BundleRejectionError::WinningBatchBidRejected(
+                    r.auction_id.clone(),
+                    r.simulated_bid_lamports,
+                )),
+                Some(Reason::SimulationFailure(r)) => Err(BundleRejectionError::SimulationFailure(
+                    r.tx_signature.clone(),
+                    r.msg.clone(),
+                )),
+                Some(Reason::InternalError(r)) => Err(BundleRejectionError::InternalError(r.msg.clone())),
+                None => return,
+            },
+            None => return,
+        };
+
+        let mut slots_guard = slots.lock().await;
+        match slots_guard.remove(&result.uuid) {
+            Some(Slot::Waiting(outcome_tx)) => {
+                let _ = outcome_tx.send(outcome);
+            }
+            _ => {
+                slots_guard.insert(result.uuid, Slot::Early(outcome));
+            }
+        }
+    }
+
+    async fn fail_all(slots: &std::sync::Arc<Mutex<HashMap<String, Slot>>>, error: BundleRejectionError) {
+        for (_, slot) in slots.lock().await.drain() {
+            if let Slot::Waiting(outcome_tx) = slot {
+                let _ = outcome_tx.send(Err(match &error {
+                    BundleRejectionError::StreamLost(msg) => BundleRejectionError::StreamLost(msg.clone()),
+                    other => BundleRejectionError::InternalError(other.to_string()),
+                }));
+            }
+        }
+    }
+}
@@ -3,7 +3,6 @@ use std::{
     time::{Duration, Instant},
 };
 
-use futures_util::StreamExt;
 use log::{info, warn};
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::{
@@ -17,13 +16,13 @@ use tonic::{
     codegen::{Body, Bytes, InterceptedService, StdError},
     Response,
     Status,
-    Streaming, transport, transport::{Channel, Endpoint},
+    transport, transport::{Channel, Endpoint},
 };
 
 use jito_protos::{
     auth::{auth_service_client::AuthServiceClient, Role},
     bundle::{
-        Accepted, Bundle, bundle_result::Result as BundleResultType, BundleResult,
+        Accepted, Bundle, bundle_result::Result as BundleResultType,
         InternalError, rejected::Reason, SimulationFailure, StateAuctionBidRejected,
         WinningBatchBidRejected,
     },
@@ -33,10 +32,18 @@ use jito_protos::{
     },
 };
 
-use crate::token_authenticator::ClientInterceptor;
+use crate::{metrics::BundleOutcome, token_authenticator::ClientInterceptor};
 
+pub mod bundle_manager;
+pub mod metrics;
+pub mod mock;
+pub mod resilient_bundle_results;
 pub mod token_authenticator;
 
+pub use bundle_manager::BundleManager;
+pub use metrics::BundleMetrics;
+pub use resilient_bundle_results::ResilientBundleResults;
+
 #[derive(Debug, Error)]
 pub enum BlockEngineConnectionError {
     #[error("transport error {0}")]
@@ -55,16 +62,23 @@ pub enum BundleRejectionError {
     SimulationFailure(String, Option<String>),
     #[error("internal error {0}")]
     InternalError(String),
+    #[error("bundle result subscription lost: {0}")]
+    StreamLost(String),
 }
 
 pub type BlockEngineConnectionResult<T> = Result<T, BlockEngineConnectionError>;
 
+/// Also returns a handle to the [`ClientInterceptor`] backing the client's
+/// bearer token, so callers can force an immediate re-auth (see
+/// [`send_bundle_no_wait_with_reauth`]) if a request still comes back
+/// unauthenticated despite the interceptor's background token refresh.
 pub async fn get_searcher_client_auth(
     block_engine_url: &str,
     auth_keypair: &Arc<Keypair>,
-) -> BlockEngineConnectionResult<
+) -> BlockEngineConnectionResult<(
     SearcherServiceClient<InterceptedService<Channel, ClientInterceptor>>,
-> {
+    ClientInterceptor,
+)> {
     let auth_channel = create_grpc_channel(block_engine_url).await?;
     let client_interceptor = ClientInterceptor::new(
         AuthServiceClient::new(auth_channel),
@@ -75,8 +89,8 @@ pub async fn get_searcher_client_auth(
 
     let searcher_channel = create_grpc_channel(block_engine_url).await?;
     let searcher_client =
-        SearcherServiceClient::with_interceptor(searcher_channel, client_interceptor);
-    Ok(searcher_client)
+        SearcherServiceClient::with_interceptor(searcher_channel, client_interceptor.clone());
+    Ok((searcher_client, client_interceptor))
 }
 
 pub async fn get_searcher_client_no_auth(
@@ -95,11 +109,17 @@ pub async fn create_grpc_channel(url: &str) -> BlockEngineConnectionResult<Chann
     Ok(endpoint.connect().await?)
 }
 
+/// `interceptor` lets this retry once with a freshly forced-reauthed token
+/// if the send comes back unauthenticated (see
+/// [`send_bundle_no_wait_with_reauth`]); pass `None` for clients that don't
+/// carry a [`ClientInterceptor`] (e.g. [`get_searcher_client_no_auth`]).
 pub async fn send_bundle_with_confirmation<T>(
     transactions: &[VersionedTransaction],
     rpc_client: &RpcClient,
     searcher_client: &mut SearcherServiceClient<T>,
-    bundle_results_subscription: &mut Streaming<BundleResult>,
+    bundle_results_subscription: &mut ResilientBundleResults,
+    metrics: &BundleMetrics,
+    interceptor: Option<&ClientInterceptor>,
 ) -> Result<(), Box<dyn std::error::Error>>
 where
     T: tonic::client::GrpcService<tonic::body::BoxBody> + Send + 'static + Clone,
@@ -111,7 +131,9 @@ where
     let bundle_signatures: Vec<Signature> =
         transactions.iter().map(|tx| tx.signatures[0]).collect();
 
-    let result = send_bundle_no_wait(transactions, searcher_client).await?;
+    let result = send_bundle_no_wait_with_reauth(transactions, searcher_client, interceptor).await?;
+    let sent_at = Instant::now();
+    let mut accepted_latency_recorded = false;
 
     // grab uuid from block engine + wait for results
     let uuid = result.into_inner().uuid;
@@ -125,19 +147,33 @@ where
 
     info!("Waiting for {wait_seconds} seconds to hear results...");
     let mut time_left = wait_seconds * 1000;
-    while let Ok(Some(Ok(results))) = timeout(
+    while let Ok(Some(next)) = timeout(
         Duration::from_millis(time_left),
-        bundle_results_subscription.next(),
+        bundle_results_subscription.recv(),
     )
     .await
     {
         let instant = Instant::now();
+        let results = match next {
+            Ok(results) => results,
+            Err(e) => return Err(Box::new(e)),
+        };
         info!("bundle results: {:?}", results);
         match results.result {
             Some(BundleResultType::Accepted(Accepted {
                 slot: _s,
                 validator_identity: _v,
-            })) => {}
+            })) => {
+                // Acceptance by the block engine isn't a terminal outcome --
+                // the bundle still has to land on-chain (confirmed below via
+                // RPC) or time out, so only the latency is recorded here.
+                // Recording a `BundleOutcome` here too would double-count
+                // this bundle against whichever terminal outcome follows.
+                if !accepted_latency_recorded {
+                    metrics.record_accepted_latency(sent_at.elapsed());
+                    accepted_latency_recorded = true;
+                }
+            }
             Some(BundleResultType::Rejected(rejected)) => {
                 match rejected.reason {
                     Some(Reason::WinningBatchBidRejected(WinningBatchBidRejected {
@@ -145,6 +181,8 @@ where
                         simulated_bid_lamports,
                         msg: _,
                     })) => {
+                        metrics.record_tip_lamports(simulated_bid_lamports);
+                        metrics.record_outcome(BundleOutcome::WinningBatchBidRejected);
                         return Err(Box::new(BundleRejectionError::WinningBatchBidRejected(
                             auction_id,
                             simulated_bid_lamports,
@@ -155,18 +193,22 @@ where
                         simulated_bid_lamports,
                         msg: _,
                     })) => {
+                        metrics.record_tip_lamports(simulated_bid_lamports);
+                        metrics.record_outcome(BundleOutcome::StateAuctionBidRejected);
                         return Err(Box::new(BundleRejectionError::StateAuctionBidRejected(
                             auction_id,
                             simulated_bid_lamports,
                         )))
                     }
                     Some(Reason::SimulationFailure(SimulationFailure { tx_signature, msg })) => {
+                        metrics.record_outcome(BundleOutcome::SimulationFailure);
                         return Err(Box::new(BundleRejectionError::SimulationFailure(
                             tx_signature,
                             msg,
                         )))
                     }
                     Some(Reason::InternalError(InternalError { msg })) => {
+                        metrics.record_outcome(BundleOutcome::InternalError);
                         return Err(Box::new(BundleRejectionError::InternalError(msg)))
                     }
                     _ => {}
@@ -186,10 +228,13 @@ where
     let results = futures_util::future::join_all(futs).await;
     if !results.iter().all(|r| matches!(r, Ok(Some(Ok(()))))) {
         warn!("Transactions in bundle did not land");
+        metrics.record_outcome(BundleOutcome::TimedOut);
         return Err(Box::new(BundleRejectionError::InternalError(
             "Searcher service did not provide bundle status in time".into(),
         )));
     }
+    metrics.record_confirmed_latency(sent_at.elapsed());
+    metrics.record_outcome(BundleOutcome::LandedViaRpc);
     info!("Bundle landed successfully");
     for sig in bundle_signatures.iter() {
         info!("https://solscan.io/tx/{}", sig);
@@ -223,3 +268,35 @@ where
         })
         .await
 }
+
+/// Like [`send_bundle_no_wait`], but if the request still comes back
+/// unauthenticated (e.g. the background refresh in `interceptor` hasn't
+/// caught up with an early token expiry), forces an immediate re-auth and
+/// retries the send exactly once before giving up. `force_reauth` only
+/// touches the interceptor's own cached token, not `searcher_client`, so
+/// this works for any `T` -- pass `None` if `searcher_client` isn't wrapped
+/// in a [`ClientInterceptor`] (nothing to retry with, so an unauthenticated
+/// error is returned as-is).
+pub async fn send_bundle_no_wait_with_reauth<T>(
+    transactions: &[VersionedTransaction],
+    searcher_client: &mut SearcherServiceClient<T>,
+    interceptor: Option<&ClientInterceptor>,
+) -> Result<Response<SendBundleResponse>, Status>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Send + 'static + Clone,
+    T::Error: Into<StdError>,
+    T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+    <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::Future: std::marker::Send,
+{
+    match send_bundle_no_wait(transactions, searcher_client).await {
+        Err(status) if status.code() == tonic::Code::Unauthenticated => match interceptor {
+            Some(interceptor) => {
+                interceptor.force_reauth().await?;
+                send_bundle_no_wait(transactions, searcher_client).await
+            }
+            None => Err(status),
+        },
+        other => other,
+    }
+}
@@ -0,0 +1,313 @@
+use std::{
+    fmt,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+/// Upper bounds (inclusive, milliseconds) of the latency histogram buckets.
+/// The final bucket catches everything above the last boundary.
+const BUCKET_BOUNDS_MS: [u64; 8] = [50, 100, 200, 400, 800, 1600, 3200, 6400];
+
+/// A lock-free latency histogram with fixed exponential buckets, tracked via
+/// a counter per bucket plus an overflow bucket for anything slower than the
+/// last boundary.
+struct LatencyHistogram {
+    buckets: [AtomicU64; BUCKET_BOUNDS_MS.len() + 1],
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self {
+            buckets: std::array::from_fn(|_| AtomicU64::new(0)),
+        }
+    }
+}
+
+impl LatencyHistogram {
+    fn record(&self, latency: Duration) {
+        let millis = latency.as_millis() as u64;
+        let bucket = BUCKET_BOUNDS_MS
+            .iter()
+            .position(|&bound| millis <= bound)
+            .unwrap_or(BUCKET_BOUNDS_MS.len());
+        self.buckets[bucket].fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn counts(&self) -> [u64; BUCKET_BOUNDS_MS.len() + 1] {
+        let mut counts = [0u64; BUCKET_BOUNDS_MS.len() + 1];
+        for (dst, bucket) in counts.iter_mut().zip(self.buckets.iter()) {
+            *dst = bucket.load(Ordering::Relaxed);
+        }
+        counts
+    }
+
+    /// Interpolates the latency below which `fraction` of samples fall,
+    /// assuming samples are spread evenly within each bucket.
+    fn percentile(&self, fraction: f64) -> Option<u64> {
+        let counts = self.counts();
+        let total: u64 = counts.iter().sum();
+        if total == 0 {
+            return None;
+        }
+
+        let target = total as f64 * fraction;
+        let mut seen_before_bucket = 0u64;
+        for (i, &count) in counts.iter().enumerate() {
+            let seen_through_bucket = seen_before_bucket + count;
+            if (seen_through_bucket as f64) >= target {
+                let lower_bound = if i == 0 { 0 } else { BUCKET_BOUNDS_MS[i - 1] };
+                let upper_bound = BUCKET_BOUNDS_MS.get(i).copied().unwrap_or(lower_bound * 2);
+
+                // Linearly interpolate assuming samples are spread evenly
+                // across the bucket's (lower_bound, upper_bound] range.
+                let position_in_bucket = if count == 0 {
+                    0.0
+                } else {
+                    (target - seen_before_bucket as f64) / count as f64
+                };
+                let interpolated = lower_bound as f64
+                    + position_in_bucket.clamp(0.0, 1.0) * (upper_bound - lower_bound) as f64;
+                return Some(interpolated.round() as u64);
+            }
+            seen_before_bucket = seen_through_bucket;
+        }
+        BUCKET_BOUNDS_MS.last().copied()
+    }
+}
+
+/// Running min/max/mean of tip lamports observed on rejected bundles that
+/// carry a `simulated_bid_lamports` field.
+struct TipLamportsStats {
+    count: AtomicU64,
+    sum: AtomicU64,
+    min: AtomicU64,
+    max: AtomicU64,
+}
+
+impl Default for TipLamportsStats {
+    fn default() -> Self {
+        Self {
+            count: AtomicU64::new(0),
+            sum: AtomicU64::new(0),
+            // `fetch_min` never raises a value, so this must start above any
+            // real tip for the first `record()` call to win.
+            min: AtomicU64::new(u64::MAX),
+            max: AtomicU64::new(0),
+        }
+    }
+}
+
+impl TipLamportsStats {
+    fn record(&self, lamports: u64) {
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.sum.fetch_add(lamports, Ordering::Relaxed);
+        self.min.fetch_min(lamports, Ordering::Relaxed);
+        self.max.fetch_max(lamports, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> Option<TipLamportsSnapshot> {
+        let count = self.count.load(Ordering::Relaxed);
+        if count == 0 {
+            return None;
+        }
+        Some(TipLamportsSnapshot {
+            min: self.min.load(Ordering::Relaxed),
+            max: self.max.load(Ordering::Relaxed),
+            mean: self.sum.load(Ordering::Relaxed) as f64 / count as f64,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TipLamportsSnapshot {
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+}
+
+/// The terminal outcome a bundle was ultimately observed to reach, as
+/// tracked by [`BundleMetrics`]. These are mutually exclusive: at most one
+/// is recorded per bundle, so `BundleMetricsSnapshot`'s win-rate can sum
+/// them directly. A bundle whose result subscription is lost while waiting
+/// records no outcome and is not counted in that total. Being accepted
+/// into the block engine's auction isn't itself terminal (the bundle still
+/// has to land or time out), so it's tracked only as a latency sample via
+/// [`BundleMetrics::record_accepted_latency`], not as an outcome here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BundleOutcome {
+    StateAuctionBidRejected,
+    WinningBatchBidRejected,
+    SimulationFailure,
+    InternalError,
+    LandedViaRpc,
+    TimedOut,
+}
+
+impl BundleOutcome {
+    const ALL: [BundleOutcome; 6] = [
+        BundleOutcome::StateAuctionBidRejected,
+        BundleOutcome::WinningBatchBidRejected,
+        BundleOutcome::SimulationFailure,
+        BundleOutcome::InternalError,
+        BundleOutcome::LandedViaRpc,
+        BundleOutcome::TimedOut,
+    ];
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|o| *o == self).unwrap()
+    }
+}
+
+impl fmt::Display for BundleOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            BundleOutcome::StateAuctionBidRejected => "state_auction_bid_rejected",
+            BundleOutcome::WinningBatchBidRejected => "winning_batch_bid_rejected",
+            BundleOutcome::SimulationFailure => "simulation_failure",
+            BundleOutcome::InternalError => "internal_error",
+            BundleOutcome::LandedViaRpc => "landed_via_rpc",
+            BundleOutcome::TimedOut => "timed_out",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Tracks bundle latency and landing-rate metrics across a searcher session.
+/// `send_bundle_with_confirmation` updates this on every call so callers can
+/// periodically print win-rate and landing-latency distributions instead of
+/// relying on one-off log lines.
+pub struct BundleMetrics {
+    accepted_latency: LatencyHistogram,
+    confirmed_latency: LatencyHistogram,
+    outcomes: [AtomicU64; BundleOutcome::ALL.len()],
+    tip_lamports: TipLamportsStats,
+}
+
+impl Default for BundleMetrics {
+    fn default() -> Self {
+        Self {
+            accepted_latency: LatencyHistogram::default(),
+            confirmed_latency: LatencyHistogram::default(),
+            outcomes: std::array::from_fn(|_| AtomicU64::new(0)),
+            tip_lamports: TipLamportsStats::default(),
+        }
+    }
+}
+
+impl BundleMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the time from `send_bundle_no_wait` returning a UUID to the
+    /// first `Accepted` result for that bundle.
+    pub fn record_accepted_latency(&self, latency: Duration) {
+        self.accepted_latency.record(latency);
+    }
+
+    /// Records the time from `send_bundle_no_wait` returning a UUID to
+    /// on-chain confirmation via `get_signature_status_with_commitment`.
+    pub fn record_confirmed_latency(&self, latency: Duration) {
+        self.confirmed_latency.record(latency);
+    }
+
+    pub fn record_outcome(&self, outcome: BundleOutcome) {
+        self.outcomes[outcome.index()].fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_tip_lamports(&self, lamports: u64) {
+        self.tip_lamports.record(lamports);
+    }
+
+    pub fn snapshot(&self) -> BundleMetricsSnapshot {
+        let mut outcome_counts = [(BundleOutcome::TimedOut, 0u64); BundleOutcome::ALL.len()];
+        for (dst, outcome) in outcome_counts.iter_mut().zip(BundleOutcome::ALL.iter()) {
+            *dst = (*outcome, self.outcomes[outcome.index()].load(Ordering::Relaxed));
+        }
+
+        BundleMetricsSnapshot {
+            accepted_latency: LatencySnapshot::from(&self.accepted_latency),
+            confirmed_latency: LatencySnapshot::from(&self.confirmed_latency),
+            outcome_counts,
+            tip_lamports: self.tip_lamports.snapshot(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct LatencySnapshot {
+    pub p50_ms: Option<u64>,
+    pub p90_ms: Option<u64>,
+    pub p99_ms: Option<u64>,
+}
+
+impl From<&LatencyHistogram> for LatencySnapshot {
+    fn from(histogram: &LatencyHistogram) -> Self {
+        Self {
+            p50_ms: histogram.percentile(0.50),
+            p90_ms: histogram.percentile(0.90),
+            p99_ms: histogram.percentile(0.99),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BundleMetricsSnapshot {
+    pub accepted_latency: LatencySnapshot,
+    pub confirmed_latency: LatencySnapshot,
+    pub outcome_counts: [(BundleOutcome, u64); BundleOutcome::ALL.len()],
+    pub tip_lamports: Option<TipLamportsSnapshot>,
+}
+
+impl fmt::Display for BundleMetricsSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total: u64 = self.outcome_counts.iter().map(|(_, c)| c).sum();
+        let landed = self
+            .outcome_counts
+            .iter()
+            .find(|(o, _)| *o == BundleOutcome::LandedViaRpc)
+            .map(|(_, c)| *c)
+            .unwrap_or(0);
+        let win_rate = if total == 0 {
+            0.0
+        } else {
+            landed as f64 / total as f64 * 100.0
+        };
+
+        writeln!(f, "bundle metrics ({total} bundle(s), {win_rate:.1}% landed):")?;
+        for (outcome, count) in self.outcome_counts.iter() {
+            writeln!(f, "  {outcome}: {count}")?;
+        }
+        writeln!(
+            f,
+            "  time-to-accepted: p50={} p90={} p99={}",
+            format_ms(self.accepted_latency.p50_ms),
+            format_ms(self.accepted_latency.p90_ms),
+            format_ms(self.accepted_latency.p99_ms),
+        )?;
+        writeln!(
+            f,
+            "  time-to-confirmed: p50={} p90={} p99={}",
+            format_ms(self.confirmed_latency.p50_ms),
+            format_ms(self.confirmed_latency.p90_ms),
+            format_ms(self.confirmed_latency.p99_ms),
+        )?;
+        if let Some(tips) = self.tip_lamports {
+            write!(
+                f,
+                "  rejected tip lamports: min={} max={} mean={:.0}",
+                tips.min, tips.max, tips.mean
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats a percentile sample as `"123ms"`, or `"n/a"` if no samples have
+/// been recorded yet.
+fn format_ms(ms: Option<u64>) -> String {
+    match ms {
+        Some(ms) => format!("{ms}ms"),
+        None => "n/a".to_string(),
+    }
+}
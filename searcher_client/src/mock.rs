@@ -0,0 +1,266 @@
+//! An in-process, in-memory implementation of the searcher gRPC service,
+//! for exercising `send_bundle_with_confirmation` and the
+//! `BundleResultType`/`Reason` matching logic without a live block engine.
+//!
+//! ```ignore
+//! let (client, server, sent) = mock::MockSearcherServiceBuilder::new()
+//!     .with_results(vec![mock::ScriptedResult::Accepted])
+//!     .build()
+//!     .await;
+//! tokio::spawn(server);
+//! // `client` is a `SearcherServiceClient<Channel>` connected over an
+//! // in-memory duplex transport, usable anywhere a real client would be.
+//! // `sent.snapshot().await` returns every `Bundle` the mock received.
+//! ```
+
+use std::{pin::Pin, sync::Arc};
+
+use jito_protos::{
+    bundle::{
+        bundle_result::Result as BundleResultType, rejected::Reason, Accepted, Bundle,
+        BundleResult, InternalError, Rejected, SimulationFailure, StateAuctionBidRejected,
+        WinningBatchBidRejected,
+    },
+    searcher::{
+        searcher_service_client::SearcherServiceClient,
+        searcher_service_server::{SearcherService, SearcherServiceServer},
+        ConnectedLeadersRequest, ConnectedLeadersResponse, GetTipAccountsRequest,
+        GetTipAccountsResponse, NextScheduledLeaderRequest, NextScheduledLeaderResponse,
+        PendingTxNotification, SendBundleRequest, SendBundleResponse,
+        SubscribeBundleResultsRequest, SubscribePendingTransactionsRequest,
+    },
+};
+use tokio::sync::{mpsc, Mutex};
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{
+    transport::{Channel, Endpoint, Server, Uri},
+    Request, Response, Status,
+};
+
+/// A handle to the bundles a [`MockSearcherService`] has received, so a test
+/// can assert on the packets it was sent.
+#[derive(Clone, Default)]
+pub struct SentBundles(Arc<Mutex<Vec<Bundle>>>);
+
+impl SentBundles {
+    pub async fn snapshot(&self) -> Vec<Bundle> {
+        self.0.lock().await.clone()
+    }
+}
+
+/// A single scripted step in a mocked bundle-result stream.
+pub enum ScriptedResult {
+    Accepted,
+    StateAuctionBidRejected { auction_id: String, simulated_bid_lamports: u64 },
+    WinningBatchBidRejected { auction_id: String, simulated_bid_lamports: u64 },
+    SimulationFailure { tx_signature: String, msg: Option<String> },
+    InternalError { msg: String },
+    /// Pauses the scripted stream for the given duration before continuing.
+    Delay(std::time::Duration),
+    /// Closes the stream, simulating a dropped connection.
+    StreamClose,
+}
+
+impl ScriptedResult {
+    fn to_bundle_result(&self, uuid: String) -> Option<BundleResult> {
+        let result = match self {
+            ScriptedResult::Accepted => BundleResultType::Accepted(Accepted {
+                slot: 0,
+                validator_identity: String::new(),
+            }),
+            ScriptedResult::StateAuctionBidRejected {
+                auction_id,
+                simulated_bid_lamports,
+            } => BundleResultType::Rejected(Rejected {
+                reason: Some(Reason::StateAuctionBidRejected(StateAuctionBidRejected {
+                    auction_id: auction_id.clone(),
+                    simulated_bid_lamports: *simulated_bid_lamports,
+                    msg: String::new(),
+                })),
+            }),
+            ScriptedResult::WinningBatchBidRejected {
+                auction_id,
+                simulated_bid_lamports,
+            } => BundleResultType::Rejected(Rejected {
+                reason: Some(Reason::WinningBatchBidRejected(WinningBatchBidRejected {
+                    auction_id: auction_id.clone(),
+                    simulated_bid_lamports: *simulated_bid_lamports,
+                    msg: String::new(),
+                })),
+            }),
+            ScriptedResult::SimulationFailure { tx_signature, msg } => {
+                BundleResultType::Rejected(Rejected {
+                    reason: Some(Reason::SimulationFailure(SimulationFailure {
+                        tx_signature: tx_signature.clone(),
+                        msg: msg.clone(),
+                    })),
+                })
+            }
+            ScriptedResult::InternalError { msg } => BundleResultType::Rejected(Rejected {
+                reason: Some(Reason::InternalError(InternalError { msg: msg.clone() })),
+            }),
+            ScriptedResult::Delay(_) | ScriptedResult::StreamClose => return None,
+        };
+        Some(BundleResult {
+            uuid,
+            result: Some(result),
+        })
+    }
+}
+
+/// Builds a [`MockSearcherService`] and a client connected to it over an
+/// in-memory duplex transport.
+#[derive(Default)]
+pub struct MockSearcherServiceBuilder {
+    script: Vec<ScriptedResult>,
+}
+
+impl MockSearcherServiceBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues the sequence of results emitted on `subscribe_bundle_results`
+    /// after a `send_bundle` request arrives. The script is played back only
+    /// if a `subscribe_bundle_results` stream is open when the request
+    /// arrives; otherwise no results are emitted for that bundle.
+    pub fn with_results(mut self, script: Vec<ScriptedResult>) -> Self {
+        self.script = script;
+        self
+    }
+
+    /// Builds the client/server pair, plus a handle to the bundles received
+    /// so far. The returned future must be spawned (e.g. `tokio::spawn(server)`)
+    /// to drive the in-memory server.
+    pub async fn build(
+        self,
+    ) -> (
+        SearcherServiceClient<Channel>,
+        impl std::future::Future<Output = ()>,
+        SentBundles,
+    ) {
+        let (client_io, server_io) = tokio::io::duplex(64 * 1024);
+        let sent_bundles = SentBundles::default();
+        let service = MockSearcherService {
+            script: Arc::new(self.script),
+            results_tx: Arc::new(Mutex::new(None)),
+            sent_bundles: sent_bundles.clone(),
+        };
+
+        let server = async move {
+            let _ = Server::builder()
+                .add_service(SearcherServiceServer::new(service))
+                .serve_with_incoming(tokio_stream::once(Ok::<_, std::io::Error>(server_io)))
+                .await;
+        };
+
+        let mut client_io = Some(client_io);
+        let channel = Endpoint::try_from("http://[::]:50051")
+            .unwrap()
+            .connect_with_connector(tower::service_fn(move |_: Uri| {
+                let client_io = client_io.take();
+                async move {
+                    client_io
+                        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::Other, "client_io reused"))
+                }
+            }))
+            .await
+            .expect("failed to connect in-memory mock channel");
+
+        (SearcherServiceClient::new(channel), server, sent_bundles)
+    }
+}
+
+/// Queues sent bundles and, for each one, plays back a scripted
+/// [`ScriptedResult`] sequence over `subscribe_bundle_results`.
+struct MockSearcherService {
+    script: Arc<Vec<ScriptedResult>>,
+    results_tx: Arc<Mutex<Option<mpsc::Sender<Result<BundleResult, Status>>>>>,
+    sent_bundles: SentBundles,
+}
+
+#[tonic::async_trait]
+impl SearcherService for MockSearcherService {
+    type SubscribeBundleResultsStream = ReceiverStream<Result<BundleResult, Status>>;
+    type SubscribePendingTransactionsStream =
+        Pin<Box<dyn futures_util::Stream<Item = Result<PendingTxNotification, Status>> + Send>>;
+
+    async fn send_bundle(
+        &self,
+        request: Request<SendBundleRequest>,
+    ) -> Result<Response<SendBundleResponse>, Status> {
+        let uuid = format!("{:x}", rand::random::<u128>());
+
+        if let Some(bundle) = request.into_inner().bundle {
+            self.sent_bundles.0.lock().await.push(bundle);
+        }
+
+        if let Some(results_tx) = self.results_tx.lock().await.clone() {
+            let script = self.script.clone();
+            let results_tx_slot = self.results_tx.clone();
+            let uuid = uuid.clone();
+            tokio::spawn(async move {
+                for step in script.iter() {
+                    match step {
+                        ScriptedResult::Delay(d) => tokio::time::sleep(*d).await,
+                        ScriptedResult::StreamClose => {
+                            // Drop the service's own sender as well, so the
+                            // stream ends once this task's clone is dropped.
+                            let mut slot = results_tx_slot.lock().await;
+                            if slot.as_ref().is_some_and(|tx| tx.same_channel(&results_tx)) {
+                                *slot = None;
+                            }
+                            break;
+                        }
+                        _ => {
+                            if let Some(result) = step.to_bundle_result(uuid.clone()) {
+                                if results_tx.send(Ok(result)).await.is_err() {
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                }
+            });
+        }
+
+        Ok(Response::new(SendBundleResponse { uuid }))
+    }
+
+    async fn subscribe_bundle_results(
+        &self,
+        _request: Request<SubscribeBundleResultsRequest>,
+    ) -> Result<Response<Self::SubscribeBundleResultsStream>, Status> {
+        let (tx, rx) = mpsc::channel(16);
+        *self.results_tx.lock().await = Some(tx);
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+
+    async fn subscribe_pending_transactions(
+        &self,
+        _request: Request<SubscribePendingTransactionsRequest>,
+    ) -> Result<Response<Self::SubscribePendingTransactionsStream>, Status> {
+        Err(Status::unimplemented("not used by the mock"))
+    }
+
+    async fn get_next_scheduled_leader(
+        &self,
+        _request: Request<NextScheduledLeaderRequest>,
+    ) -> Result<Response<NextScheduledLeaderResponse>, Status> {
+        Err(Status::unimplemented("not used by the mock"))
+    }
+
+    async fn get_connected_leaders(
+        &self,
+        _request: Request<ConnectedLeadersRequest>,
+    ) -> Result<Response<ConnectedLeadersResponse>, Status> {
+        Err(Status::unimplemented("not used by the mock"))
+    }
+
+    async fn get_tip_accounts(
+        &self,
+        _request: Request<GetTipAccountsRequest>,
+    ) -> Result<Response<GetTipAccountsResponse>, Status> {
+        Err(Status::unimplemented("not used by the mock"))
+    }
+}
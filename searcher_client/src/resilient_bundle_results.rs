@@ -0,0 +1,159 @@
+use std::time::Duration;
+
+use log::warn;
+use rand::Rng;
+use tokio::{
+    sync::mpsc,
+    time::{sleep, Instant},
+};
+use tonic::{
+    codegen::{Body, Bytes, StdError},
+    Streaming,
+};
+
+use jito_protos::{
+    bundle::BundleResult,
+    searcher::{searcher_service_client::SearcherServiceClient, SubscribeBundleResultsRequest},
+};
+
+use crate::BundleRejectionError;
+
+const INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+const MAX_BACKOFF: Duration = Duration::from_secs(5);
+
+/// A bundle result, or the reason the subscription gave up trying to deliver one.
+pub type BundleResultOrLost = Result<BundleResult, BundleRejectionError>;
+
+/// Wraps a searcher client's bundle-result subscription and transparently
+/// re-establishes it if the block engine connection drops, so a long-lived
+/// searcher session doesn't silently lose results to a transport hiccup.
+///
+/// Results are forwarded over an internal channel, drained with [`Self::recv`],
+/// instead of handing callers the raw `Streaming<BundleResult>`. Every
+/// `BundleResult` carries its own `uuid`, so a result that arrives for a
+/// bundle that was in-flight before a reconnect is still matched correctly
+/// by callers that key off that field -- no extra bookkeeping is needed here.
+pub struct ResilientBundleResults {
+    results_rx: mpsc::Receiver<BundleResultOrLost>,
+}
+
+impl ResilientBundleResults {
+    /// Spawns the background reconnect loop. `reconnect_deadline` bounds how
+    /// long repeated reconnect attempts are allowed to take before a
+    /// [`BundleRejectionError::StreamLost`] is surfaced to the caller.
+    pub fn new<T>(searcher_client: SearcherServiceClient<T>, reconnect_deadline: Duration) -> Self
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody> + Send + 'static + Clone,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+        <T as tonic::client::GrpcService<tonic::body::BoxBody>>::Future: std::marker::Send,
+    {
+        let (results_tx, results_rx) = mpsc::channel(1_000);
+
+        tokio::spawn(Self::run(searcher_client, results_tx, reconnect_deadline));
+
+        Self { results_rx }
+    }
+
+    /// Receives the next bundle result, or the error that ended the subscription.
+    pub async fn recv(&mut self) -> Option<BundleResultOrLost> {
+        self.results_rx.recv().await
+    }
+
+    async fn run<T>(
+        mut searcher_client: SearcherServiceClient<T>,
+        results_tx: mpsc::Sender<BundleResultOrLost>,
+        reconnect_deadline: Duration,
+    ) where
+        T: tonic::client::GrpcService<tonic::body::BoxBody> + Send + 'static + Clone,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+        <T as tonic::client::GrpcService<tonic::body::BoxBody>>::Future: std::marker::Send,
+    {
+        let mut backoff = INITIAL_BACKOFF;
+        let mut gap_started_at: Option<Instant> = None;
+
+        loop {
+            match searcher_client
+                .subscribe_bundle_results(SubscribeBundleResultsRequest {})
+                .await
+            {
+                Ok(resp) => {
+                    match Self::forward_until_dropped_or_lost(resp.into_inner(), &results_tx).await
+                    {
+                        StreamOutcome::ReceiverDropped => return,
+                        // Only a stream that actually delivered a result counts as
+                        // recovered -- a server that accepts the subscription and
+                        // then immediately drops it shouldn't reset the deadline
+                        // on every loop iteration and defeat `reconnect_deadline`.
+                        StreamOutcome::EndedAfterProgress => {
+                            backoff = INITIAL_BACKOFF;
+                            gap_started_at = None;
+                            warn!("bundle result stream ended, attempting to reconnect");
+                        }
+                        StreamOutcome::EndedImmediately => {
+                            warn!(
+                                "bundle result stream closed immediately after subscribing, attempting to reconnect"
+                            );
+                        }
+                    }
+                }
+                Err(status) => {
+                    warn!("failed to (re)subscribe to bundle results: {status}");
+                }
+            }
+
+            let gap_started_at = *gap_started_at.get_or_insert_with(Instant::now);
+            if gap_started_at.elapsed() > reconnect_deadline {
+                let _ = results_tx
+                    .send(Err(BundleRejectionError::StreamLost(
+                        "exhausted reconnect backoff".to_string(),
+                    )))
+                    .await;
+                return;
+            }
+
+            let jitter = Duration::from_millis(rand::thread_rng().gen_range(0..50));
+            sleep(backoff + jitter).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    }
+
+    /// Forwards results until the stream ends.
+    async fn forward_until_dropped_or_lost(
+        mut stream: Streaming<BundleResult>,
+        results_tx: &mpsc::Sender<BundleResultOrLost>,
+    ) -> StreamOutcome {
+        let mut forwarded_any = false;
+        loop {
+            match stream.message().await {
+                Ok(Some(result)) => {
+                    if results_tx.send(Ok(result)).await.is_err() {
+                        return StreamOutcome::ReceiverDropped;
+                    }
+                    forwarded_any = true;
+                }
+                Ok(None) | Err(_) => {
+                    return if forwarded_any {
+                        StreamOutcome::EndedAfterProgress
+                    } else {
+                        StreamOutcome::EndedImmediately
+                    };
+                }
+            }
+        }
+    }
+}
+
+/// How a single bundle-result subscription attempt ended.
+enum StreamOutcome {
+    /// The receiving end was dropped; the background task should exit.
+    ReceiverDropped,
+    /// The stream ended (or errored) after forwarding at least one result.
+    EndedAfterProgress,
+    /// The stream ended (or errored) without ever forwarding a result, e.g.
+    /// a server that accepts the subscription and then immediately drops it.
+    EndedImmediately,
+}
@@ -0,0 +1,218 @@
+use std::{sync::Arc, time::Duration};
+
+use arc_swap::ArcSwap;
+use jito_protos::auth::{
+    auth_service_client::AuthServiceClient, GenerateAuthChallengeRequest,
+    GenerateAuthTokensRequest, RefreshAccessTokenRequest, Role, Token,
+};
+use log::{error, info};
+use solana_sdk::signature::{Keypair, Signer};
+use tokio::sync::RwLock;
+use tonic::{service::Interceptor, transport::Channel, Request, Status};
+
+/// How long before an access token's expiry the background refresh task
+/// wakes up and fetches a new one.
+const REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// The refresh token and expiry bookkeeping needed to keep the access token
+/// current; only touched from async context (the refresh loop / reauth).
+struct TokenState {
+    expires_at_unix_secs: i64,
+    refresh_token: String,
+}
+
+/// A freshly issued access + refresh token pair.
+struct CachedToken {
+    access_token: String,
+    state: TokenState,
+}
+
+/// Attaches a bearer token to every outgoing searcher/auth request and keeps
+/// that token fresh for the lifetime of the client. Long-running searchers
+/// no longer need to rebuild their client when the access token expires: a
+/// background task refreshes it a [`REFRESH_MARGIN`] before expiry, and any
+/// request that still hits an unauthenticated error can force an immediate
+/// re-auth via [`ClientInterceptor::force_reauth`].
+///
+/// The access token itself lives behind an [`ArcSwap`] rather than the async
+/// `RwLock` guarding [`TokenState`], so the synchronous [`Interceptor::call`]
+/// can always grab a consistent snapshot instead of racing the background
+/// refresh and falling back to an empty token.
+#[derive(Clone)]
+pub struct ClientInterceptor {
+    auth_service_client: AuthServiceClient<Channel>,
+    auth_keypair: Arc<Keypair>,
+    role: Role,
+    access_token: Arc<ArcSwap<str>>,
+    state: Arc<RwLock<TokenState>>,
+}
+
+impl ClientInterceptor {
+    pub async fn new(
+        mut auth_service_client: AuthServiceClient<Channel>,
+        auth_keypair: &Arc<Keypair>,
+        role: Role,
+    ) -> Result<Self, Status> {
+        let cached_token = Self::authenticate(&mut auth_service_client, auth_keypair, role).await?;
+
+        let interceptor = Self {
+            auth_service_client,
+            auth_keypair: auth_keypair.clone(),
+            role,
+            access_token: Arc::new(ArcSwap::new(Arc::from(cached_token.access_token))),
+            state: Arc::new(RwLock::new(cached_token.state)),
+        };
+
+        tokio::spawn(interceptor.clone().refresh_loop());
+
+        Ok(interceptor)
+    }
+
+    /// Forces an immediate, synchronous re-authentication, bypassing the
+    /// refresh token. Used by [`crate::send_bundle_no_wait_with_reauth`] to
+    /// retry a searcher RPC once with a fresh token after it comes back
+    /// unauthenticated, and by [`Self::refresh_loop`] as a fallback once the
+    /// refresh token itself has expired.
+    pub async fn force_reauth(&self) -> Result<(), Status> {
+        let mut auth_service_client = self.auth_service_client.clone();
+        let cached_token =
+            Self::authenticate(&mut auth_service_client, &self.auth_keypair, self.role).await?;
+        self.access_token.store(Arc::from(cached_token.access_token));
+        *self.state.write().await = cached_token.state;
+        Ok(())
+    }
+
+    /// Runs forever in the background, waking up a [`REFRESH_MARGIN`]
+    /// before the cached token's expiry and swapping in a newly refreshed
+    /// one so requests never observe an expired token. If the refresh token
+    /// has itself expired, `refresh` will keep failing every iteration, so
+    /// this falls back to a full [`Self::force_reauth`] rather than retrying
+    /// the same broken refresh token forever.
+    async fn refresh_loop(self) {
+        loop {
+            let sleep_for = {
+                let state = self.state.read().await;
+                seconds_until_refresh(state.expires_at_unix_secs)
+            };
+            tokio::time::sleep(sleep_for).await;
+
+            match self.refresh().await {
+                Ok(()) => info!("refreshed block engine auth token"),
+                Err(status) => {
+                    error!(
+                        "failed to refresh block engine auth token, falling back to full re-auth: {status}"
+                    );
+                    match self.force_reauth().await {
+                        Ok(()) => info!("re-authenticated block engine auth token"),
+                        Err(status) => {
+                            error!("failed to re-authenticate block engine auth token, will retry: {status}");
+                            // Back off briefly so a persistent failure doesn't spin the loop.
+                            tokio::time::sleep(Duration::from_secs(5)).await;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    async fn refresh(&self) -> Result<(), Status> {
+        let refresh_token = self.state.read().await.refresh_token.clone();
+        let mut auth_service_client = self.auth_service_client.clone();
+        let response = auth_service_client
+            .refresh_access_token(RefreshAccessTokenRequest { refresh_token })
+            .await?
+            .into_inner();
+        let access_token = response.access_token.ok_or_else(|| {
+            Status::internal("refresh_access_token response missing access_token")
+        })?;
+
+        let mut state = self.state.write().await;
+        state.expires_at_unix_secs = expiry_unix_secs(&access_token);
+        self.access_token.store(Arc::from(access_token.value));
+        Ok(())
+    }
+
+    /// Performs the full challenge/response handshake and returns the
+    /// resulting access + refresh tokens.
+    async fn authenticate(
+        auth_service_client: &mut AuthServiceClient<Channel>,
+        auth_keypair: &Arc<Keypair>,
+        role: Role,
+    ) -> Result<CachedToken, Status> {
+        let pubkey = auth_keypair.pubkey();
+        let challenge_resp = auth_service_client
+            .generate_auth_challenge(GenerateAuthChallengeRequest {
+                role: role as i32,
+                pubkey: pubkey.to_bytes().to_vec(),
+            })
+            .await?
+            .into_inner();
+
+        let challenge = format!("{}-{}", pubkey, challenge_resp.challenge);
+        let signed_challenge = auth_keypair.sign_message(challenge.as_bytes());
+
+        let tokens_resp = auth_service_client
+            .generate_auth_tokens(GenerateAuthTokensRequest {
+                challenge,
+                client_pubkey: pubkey.to_bytes().to_vec(),
+                signed_challenge: signed_challenge.as_ref().to_vec(),
+            })
+            .await?
+            .into_inner();
+
+        let access_token = tokens_resp
+            .access_token
+            .ok_or_else(|| Status::internal("generate_auth_tokens response missing access_token"))?;
+        let refresh_token = tokens_resp
+            .refresh_token
+            .ok_or_else(|| Status::internal("generate_auth_tokens response missing refresh_token"))?;
+
+        Ok(CachedToken {
+            access_token: access_token.value,
+            state: TokenState {
+                expires_at_unix_secs: expiry_unix_secs(&access_token),
+                refresh_token: refresh_token.value,
+            },
+        })
+    }
+}
+
+impl Interceptor for ClientInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let access_token = self.access_token.load();
+
+        request.metadata_mut().insert(
+            "authorization",
+            format!("Bearer {access_token}")
+                .parse()
+                .map_err(|_| Status::invalid_argument("invalid access token"))?,
+        );
+        Ok(request)
+    }
+}
+
+/// Fallback freshness window used when a token response omits
+/// `expires_at_utc` entirely. Treating a missing expiry as "already
+/// expired" (i.e. `0`) would make `refresh_loop` busy-spin against the auth
+/// service with no delay between attempts, so instead it's treated as
+/// "refresh well in the future."
+const MISSING_EXPIRY_FALLBACK: Duration = Duration::from_secs(3600);
+
+fn expiry_unix_secs(token: &Token) -> i64 {
+    match token.expires_at_utc.as_ref() {
+        Some(ts) => ts.seconds,
+        None => now_unix_secs() + MISSING_EXPIRY_FALLBACK.as_secs() as i64,
+    }
+}
+
+fn now_unix_secs() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
+fn seconds_until_refresh(expires_at_unix_secs: i64) -> Duration {
+    let refresh_at = expires_at_unix_secs - REFRESH_MARGIN.as_secs() as i64;
+    Duration::from_secs((refresh_at - now_unix_secs()).max(0) as u64)
+}
@@ -0,0 +1,249 @@
+//! Exercises `send_bundle_with_confirmation` against the in-process
+//! [`mock::MockSearcherService`], covering every `BundleRejectionError`
+//! arm that a rejection reason maps to (`StreamLost` is not exercised here),
+//! the `JITO_BUNDLE_RESULT_WAIT_SECONDS` timeout path, and the RPC fallback
+//! it leads to.
+
+use jito_protos::searcher::searcher_service_client::SearcherServiceClient;
+use searcher_client::{
+    mock::{MockSearcherServiceBuilder, ScriptedResult},
+    resilient_bundle_results::ResilientBundleResults,
+    send_bundle_with_confirmation, BundleMetrics, BundleRejectionError,
+};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{signature::Keypair, signer::Signer, system_transaction, transaction::VersionedTransaction};
+use wiremock::{
+    matchers::method, Mock, MockServer, ResponseTemplate,
+};
+
+/// A single no-op transaction, just enough to exercise the send/confirm path.
+fn dummy_transaction() -> VersionedTransaction {
+    let payer = Keypair::new();
+    VersionedTransaction::from(system_transaction::transfer(
+        &payer,
+        &payer.pubkey(),
+        1,
+        solana_sdk::hash::Hash::default(),
+    ))
+}
+
+/// An RPC server that always reports every signature it's asked about as
+/// landed (or never landed, if `landed` is `false`).
+async fn rpc_client_reporting(landed: bool) -> (MockServer, RpcClient) {
+    let server = MockServer::start().await;
+    let value = if landed {
+        serde_json::json!([{ "slot": 1, "confirmations": 10, "err": null, "confirmationStatus": "confirmed" }])
+    } else {
+        serde_json::json!([null])
+    };
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": { "context": { "slot": 1 }, "value": value },
+        })))
+        .mount(&server)
+        .await;
+    let rpc_client = RpcClient::new(server.uri());
+    (server, rpc_client)
+}
+
+async fn mocked_client_and_subscription(
+    script: Vec<ScriptedResult>,
+) -> (
+    SearcherServiceClient<tonic::transport::Channel>,
+    ResilientBundleResults,
+) {
+    let (client, server, _sent) = MockSearcherServiceBuilder::new()
+        .with_results(script)
+        .build()
+        .await;
+    tokio::spawn(server);
+    let subscription = ResilientBundleResults::new(client.clone(), std::time::Duration::from_secs(1));
+    (client, subscription)
+}
+
+#[tokio::test]
+async fn records_the_sent_bundle() {
+    let (client, server, sent) = MockSearcherServiceBuilder::new()
+        .with_results(vec![ScriptedResult::Accepted])
+        .build()
+        .await;
+    tokio::spawn(server);
+    let mut subscription = ResilientBundleResults::new(client.clone(), std::time::Duration::from_secs(1));
+    let mut searcher_client = client;
+
+    let (_rpc_server, rpc_client) = rpc_client_reporting(true).await;
+    let metrics = BundleMetrics::new();
+    let transactions = vec![dummy_transaction()];
+
+    send_bundle_with_confirmation(
+        &transactions,
+        &rpc_client,
+        &mut searcher_client,
+        &mut subscription,
+        &metrics,
+        None,
+    )
+    .await
+    .expect("bundle should be accepted and land");
+
+    let received = sent.snapshot().await;
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].packets.len(), transactions.len());
+}
+
+#[tokio::test]
+async fn state_auction_bid_rejected_surfaces_as_rejection_error() {
+    let (mut client, mut subscription) = mocked_client_and_subscription(vec![
+        ScriptedResult::StateAuctionBidRejected {
+            auction_id: "auction-1".to_string(),
+            simulated_bid_lamports: 1_000,
+        },
+    ])
+    .await;
+    let (_rpc_server, rpc_client) = rpc_client_reporting(true).await;
+    let metrics = BundleMetrics::new();
+
+    let err = send_bundle_with_confirmation(
+        &[dummy_transaction()],
+        &rpc_client,
+        &mut client,
+        &mut subscription,
+        &metrics,
+        None,
+    )
+    .await
+    .expect_err("rejected bundle should surface an error");
+
+    assert!(err
+        .downcast_ref::<BundleRejectionError>()
+        .is_some_and(|e| matches!(e, BundleRejectionError::StateAuctionBidRejected(..))));
+}
+
+#[tokio::test]
+async fn winning_batch_bid_rejected_surfaces_as_rejection_error() {
+    let (mut client, mut subscription) = mocked_client_and_subscription(vec![
+        ScriptedResult::WinningBatchBidRejected {
+            auction_id: "auction-1".to_string(),
+            simulated_bid_lamports: 1_000,
+        },
+    ])
+    .await;
+    let (_rpc_server, rpc_client) = rpc_client_reporting(true).await;
+    let metrics = BundleMetrics::new();
+
+    let err = send_bundle_with_confirmation(
+        &[dummy_transaction()],
+        &rpc_client,
+        &mut client,
+        &mut subscription,
+        &metrics,
+        None,
+    )
+    .await
+    .expect_err("rejected bundle should surface an error");
+
+    assert!(err
+        .downcast_ref::<BundleRejectionError>()
+        .is_some_and(|e| matches!(e, BundleRejectionError::WinningBatchBidRejected(..))));
+}
+
+#[tokio::test]
+async fn simulation_failure_surfaces_as_rejection_error() {
+    let (mut client, mut subscription) = mocked_client_and_subscription(vec![
+        ScriptedResult::SimulationFailure {
+            tx_signature: "sig".to_string(),
+            msg: Some("simulation blew up".to_string()),
+        },
+    ])
+    .await;
+    let (_rpc_server, rpc_client) = rpc_client_reporting(true).await;
+    let metrics = BundleMetrics::new();
+
+    let err = send_bundle_with_confirmation(
+        &[dummy_transaction()],
+        &rpc_client,
+        &mut client,
+        &mut subscription,
+        &metrics,
+        None,
+    )
+    .await
+    .expect_err("rejected bundle should surface an error");
+
+    assert!(err
+        .downcast_ref::<BundleRejectionError>()
+        .is_some_and(|e| matches!(e, BundleRejectionError::SimulationFailure(..))));
+}
+
+#[tokio::test]
+async fn internal_error_surfaces_as_rejection_error() {
+    let (mut client, mut subscription) = mocked_client_and_subscription(vec![
+        ScriptedResult::InternalError {
+            msg: "block engine fell over".to_string(),
+        },
+    ])
+    .await;
+    let (_rpc_server, rpc_client) = rpc_client_reporting(true).await;
+    let metrics = BundleMetrics::new();
+
+    let err = send_bundle_with_confirmation(
+        &[dummy_transaction()],
+        &rpc_client,
+        &mut client,
+        &mut subscription,
+        &metrics,
+        None,
+    )
+    .await
+    .expect_err("rejected bundle should surface an error");
+
+    assert!(err
+        .downcast_ref::<BundleRejectionError>()
+        .is_some_and(|e| matches!(e, BundleRejectionError::InternalError(..))));
+}
+
+/// Exercises the `JITO_BUNDLE_RESULT_WAIT_SECONDS` timeout path and the RPC
+/// fallback it leads to. Run as one test (rather than several) since both
+/// scenarios mutate the shared process environment variable and would
+/// otherwise race against each other under the test harness's default
+/// parallelism.
+#[tokio::test]
+async fn timeout_falls_back_to_rpc_status() {
+    std::env::set_var("JITO_BUNDLE_RESULT_WAIT_SECONDS", "1");
+
+    // No `Accepted`/`Rejected` ever arrives, so the subscription wait times
+    // out and `send_bundle_with_confirmation` must fall back to RPC.
+    let (mut client, mut subscription) = mocked_client_and_subscription(vec![]).await;
+    let (_rpc_server, rpc_client) = rpc_client_reporting(true).await;
+    let metrics = BundleMetrics::new();
+    send_bundle_with_confirmation(
+        &[dummy_transaction()],
+        &rpc_client,
+        &mut client,
+        &mut subscription,
+        &metrics,
+        None,
+    )
+    .await
+    .expect("RPC fallback should report the bundle as landed");
+
+    let (mut client, mut subscription) = mocked_client_and_subscription(vec![]).await;
+    let (_rpc_server, rpc_client) = rpc_client_reporting(false).await;
+    let err = send_bundle_with_confirmation(
+        &[dummy_transaction()],
+        &rpc_client,
+        &mut client,
+        &mut subscription,
+        &metrics,
+        None,
+    )
+    .await
+    .expect_err("RPC fallback should report the bundle as not landed");
+    assert!(err
+        .downcast_ref::<BundleRejectionError>()
+        .is_some_and(|e| matches!(e, BundleRejectionError::InternalError(..))));
+
+    std::env::remove_var("JITO_BUNDLE_RESULT_WAIT_SECONDS");
+}